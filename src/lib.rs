@@ -8,6 +8,16 @@
 //! The `ward!` macro, on the other hand, doesn't force the creation of a variable, it only returns
 //! the value that the `guard!` variable would place into a variable. As such, it's a more flexible
 //! version of the `guard!` macro; and probably also somewhat more Rustic.
+//!
+//! Both macros also accept an arbitrary refutable pattern in place of the implied `Some(x)`, e.g.
+//! `ward!(Ok(x) = result)` or `guard!(let Point { x, y } = point)`; see their documentation for
+//! details.
+//!
+//! When the scrutinee is a `Result`, the `else |e| { .. }` form binds the `Err` payload for the
+//! `else` branch to inspect, e.g. `ward!(parse(s), else |e| { log::warn!("{e}"); return })`.
+//!
+//! [`ensure!`] (aliased as [`guard_that!`]) rounds out the family for the common case where
+//! there's a boolean condition to check rather than a value to match against.
 
 /// Returns the contents of a `Option<T>`'s `Some(T)`, otherwise it returns early
 /// from the function. Can alternatively have an `else` branch, or an alternative "early return"
@@ -89,11 +99,86 @@
 /// }
 /// # }
 /// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // ward! isn't limited to Option; any refutable pattern works, as long as you spell it out
+/// // with `$pattern = $expr` instead of the implied `Some(x)` pattern.
+/// let sut: Result<&str, &str> = Ok("test");
+/// let res = ward!(Ok(x) = sut);
+/// assert_eq!(res, "test");
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // Patterns with more than one binding are returned as a tuple.
+/// let sut = Some((1, 2));
+/// let (a, b) = ward!(Some((a, b)) = sut);
+/// assert_eq!(a, 1);
+/// assert_eq!(b, 2);
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // A unit-like pattern, such as `None` or a fieldless enum variant, binds nothing at all;
+/// // ward! still works as a pure match check in that case.
+/// enum Light { Red, Green }
+/// let sut = Light::Red;
+/// ward!(Light::Green = sut, else { return; });
+/// unreachable!();
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // A bare `None` (no enclosing path) is a unit-like pattern too, not a binding name.
+/// let sut: Option<i32> = Some(7);
+/// ward!(None = sut, else { return; });
+/// unreachable!();
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // When the scrutinee is a Result, `else |e| { .. }` binds the Err payload for the else
+/// // branch, instead of discarding it like a plain `else { .. }` would.
+/// fn parse(s: &str) -> Result<i32, core::num::ParseIntError> {
+///     let n = ward!(s.parse(), else |e| {
+///         println!("failed to parse {}: {}", s, e);
+///         return Err(e);
+///     });
+///     Ok(n)
+/// }
+/// assert_eq!(parse("42"), Ok(42));
+/// assert!(parse("nope").is_err());
+/// # }
+/// ```
+/// The `else |e| { .. }` form only works when the `Ok` side of the pattern is irrefutable, i.e.
+/// when it matches every possible `Ok` value (`Ok(x)`, `Ok((a, b))`, `Ok(Point { x, y })`, ...).
+/// `$e` can only ever be the `Err` payload, so there's nothing sensible to bind it to for an `Ok`
+/// value that the pattern itself doesn't cover; narrowing the `Ok` side further, e.g. to a
+/// sub-range, is rejected at compile time rather than silently miscompiling:
+/// ```compile_fail
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// let sut: Result<i32, &str> = Ok(1);
+/// // error[E0004]: non-exhaustive patterns: `Ok(i32::MIN..=0_i32)` and `Ok(6_i32..=i32::MAX)` not covered
+/// let n = ward!(Ok(1..=5) = sut, else |e| { return; });
+/// # }
+/// ```
+/// Use a plain `else { .. }` instead if you need to narrow the `Ok` payload; it doesn't bind `$e`,
+/// so it doesn't need the pattern to be exhaustive over `Ok`.
 #[macro_export]
 macro_rules! ward {
-    ($o:expr) => ($crate::ward!($o, else { return; }));
-    ($o:expr, else $body:block) => { if let Some(x) = $o { x } else { $body }; };
-    ($o:expr, $early:stmt) => ($crate::ward!($o, else { $early }));
+    ($($t:tt)+) => { $crate::__ward_split!(Expr, [] $($t)+) };
 }
 
 /// Creates a variable with the contents of a `Option<T>`'s `Some(T)`, otherwise it returns early
@@ -163,12 +248,684 @@ macro_rules! ward {
 /// assert_eq!(sut, None);
 /// # }
 /// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // guard! accepts any refutable pattern, not just an implied Some(x); every name the
+/// // pattern binds is hoisted into the enclosing scope.
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Square { side: f64 },
+/// }
+/// let sut = Shape::Circle { radius: 2.0 };
+/// guard!(let Shape::Circle { radius } = sut);
+/// assert_eq!(radius, 2.0);
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // Multiple bindings from tuples, slices or structs are all hoisted individually.
+/// let sut = Some((1, "two"));
+/// guard!(let Some((a, b)) = sut);
+/// assert_eq!(a, 1);
+/// assert_eq!(b, "two");
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // A `mut` qualifier on a sub-binding carries over to the hoisted outer variable, so it can
+/// // still be mutated afterwards.
+/// let sut: Result<i32, &str> = Ok(1);
+/// guard!(let Ok(mut x) = sut);
+/// x += 1;
+/// assert_eq!(x, 2);
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // When the scrutinee is a Result, `else |e| { .. }` binds the Err payload for the else
+/// // branch, instead of discarding it like a plain `else { .. }` would.
+/// fn parse(s: &str) -> Result<i32, core::num::ParseIntError> {
+///     guard!(let n = s.parse(), else |e| {
+///         println!("failed to parse {}: {}", s, e);
+///         return Err(e);
+///     });
+///     Ok(n)
+/// }
+/// assert_eq!(parse("42"), Ok(42));
+/// assert!(parse("nope").is_err());
+/// # }
+/// ```
 #[macro_export]
 macro_rules! guard {
     (let $result:ident = $o:expr) => ($crate::guard!(let $result = $o, else { return; }));
+    (let $result:ident = $o:expr, else |$e:ident| $body:block) => { let $result = ward!($o, else |$e| $body); };
     (let $result:ident = $o:expr, else $body:block) => { let $result = ward!($o, else $body); };
     (let $result:ident = $o:expr, $early:stmt) => ($crate::guard!(let $result = $o, else { $early }));
     (let mut $result:ident = $o:expr) => ($crate::guard!(let mut $result = $o, else { return; }));
+    (let mut $result:ident = $o:expr, else |$e:ident| $body:block) => { let mut $result = ward!($o, else |$e| $body); };
     (let mut $result:ident = $o:expr, else $body:block) => { let mut $result = ward!($o, else $body); };
     (let mut $result:ident = $o:expr, $early:stmt) => ($crate::guard!(let $result = $o, else { $early }));
+
+    (let $($t:tt)+) => { $crate::__ward_split!(Let, [] $($t)+) };
+}
+
+// Internal: finds the top-level `=` that separates an explicit pattern from its scrutinee, e.g.
+// in `Ok(x) = result, else { .. }`. This can't be done with a single `$($p:tt)+ = $o:expr` arm,
+// because `tt` repetitions are greedy and a bare `=` is itself a valid `tt`, which rustc rejects
+// as a locally ambiguous matcher. Instead this munches one token at a time, checking for a
+// top-level `=` or `,` before consuming anything else. A `,` found before any `=` means there
+// was no explicit pattern at all, just the classic `Option`-only shorthand, which is desugared to
+// an implied `Some(x)` pattern — unless the trailing clause is the error-binding `else |e| $body`
+// form, in which case the scrutinee is a `Result` instead, so the shorthand desugars to `Ok(x)`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_split {
+    ($mode:tt, [$($pat:tt)+] = $($rest:tt)*) => {
+        $crate::__ward_finish!($mode, ($($pat)+), $($rest)*)
+    };
+    ($mode:tt, [$($pat:tt)*] , else |$e:ident| $body:block) => {
+        $crate::__ward_finish!($mode, (Ok(__ward_bound)), $($pat)*, else |$e| $body)
+    };
+    ($mode:tt, [$($pat:tt)*] , $($rest:tt)*) => {
+        $crate::__ward_finish!($mode, (Some(__ward_bound)), $($pat)*, $($rest)*)
+    };
+    ($mode:tt, [$($pat:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__ward_split!($mode, [$($pat)* $next] $($rest)*)
+    };
+    ($mode:tt, [$($pat:tt)*]) => {
+        $crate::__ward_finish!($mode, (Some(__ward_bound)), $($pat)*)
+    };
+}
+
+// Internal: once the scrutinee is known to have no more top-level `=`/`,` to worry about, this
+// parses it (and the optional `else`/early-return clause) exactly like the original `ward!`/
+// `guard!` grammar did, before handing off to the binding muncher below.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_finish {
+    ($mode:tt, $pat:tt, $o:expr) => {
+        $crate::__ward_finish!($mode, $pat, $o, else { return; })
+    };
+    ($mode:tt, $pat:tt, $o:expr, else |$e:ident| $body:block) => {
+        $crate::__ward_munch!($mode, [], $pat, $o, $body, ($e))
+    };
+    ($mode:tt, $pat:tt, $o:expr, else $body:block) => {
+        $crate::__ward_munch!($mode, [], $pat, $o, $body, ())
+    };
+    ($mode:tt, $pat:tt, $o:expr, $early:stmt) => {
+        $crate::__ward_finish!($mode, $pat, $o, else { $early })
+    };
+}
+
+// Internal: collects the identifiers a pattern's token tree introduces in binding position,
+// while carrying the original pattern, the scrutinee and the `else` block along unchanged.
+// `$mode` picks the shape of the final expansion: `Expr` produces a plain `if let` expression
+// (or a `match`, for the `else |e|` form) (used by `ward!`), `Let` produces a full `let`
+// statement that hoists every binding into the enclosing scope (used by `guard!`).
+//
+// A single binding is special-cased to expand to just that value/name rather than a one-element
+// tuple, so `ward!`/`guard!` keep behaving exactly as they always did for the common `Some(x)`
+// case.
+//
+// The actual token-tree walk lives in `__ward_bind_munch!`, shared with `ward_unwrap!`/
+// `guard_unwrap!` below, so it's only maintained in one place.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_munch {
+    // A bare identifier as the *whole* explicit pattern, e.g. `None` or a unit struct/constant
+    // name, is never a binding: the muncher can't tell a fresh binding from a unit-like pattern
+    // apart by looking at the token alone (that's ordinary Rust name resolution, which macros
+    // don't have access to), but `guard!`'s own `let $name = ..` shorthand already owns "bind the
+    // whole value to a name", so an explicit pattern consisting of nothing but one identifier is
+    // unambiguous: it's always a zero-binding match. This has to be checked before the general
+    // entry point below, since `$($orig:tt)+` would otherwise swallow it first.
+    ($mode:tt, [], ($id:ident), $o:expr, $body:block, $err:tt) => {
+        $crate::__ward_bind_munch!(
+            __ward_finish_done,
+            [$mode, ($id), $o, $body, $err],
+            [],
+            [],
+        )
+    };
+
+    // Entry point: unwrap the parenthesized pattern into the raw tokens the shared muncher
+    // scans, while keeping the parenthesized form around to splice into the final `if let`.
+    // `$err` is `()` for the plain `else $body`/early-return forms, or `($e:ident)` when the
+    // caller used the `else |e| $body` form and wants the `Err` payload bound to `e`.
+    ($mode:tt, [], ($($orig:tt)+), $o:expr, $body:block, $err:tt) => {
+        $crate::__ward_bind_munch!(
+            __ward_finish_done,
+            [$mode, ($($orig)+), $o, $body, $err],
+            [],
+            [],
+            $($orig)+
+        )
+    };
+}
+
+// Internal: the terminal step of `__ward_bind_munch!` for `ward!`/`guard!`: called once the
+// pattern has been fully scanned, with the plain binding names (`$bind`) and, in lockstep, the
+// same names with any `mut` qualifier they were declared with (`$qual`) still attached.
+//
+// `Expr` mode never hoists a binding into the enclosing scope, so it only needs `$bind`; `$qual`
+// is there so `Expr`/`Let` can share the same call shape, but is otherwise ignored. `Let` mode
+// does hoist, so it has to re-declare each binding with its original `mut` qualifier intact, or
+// a caller's `let mut x = ...` inside the pattern would compile but mutating `x` afterwards
+// wouldn't.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_finish_done {
+    // No error binding: emit an `if let ... else` as before.
+    (Expr, $orig:tt, $o:expr, $body:block, (), [$bind:ident], [$($qual:tt)*]) => {
+        if let $orig = $o { $bind } else { $body }
+    };
+    (Expr, $orig:tt, $o:expr, $body:block, (), [$($bind:ident),*], [$($qual:tt)*]) => {
+        if let $orig = $o { ($($bind),*) } else { $body }
+    };
+    // The outer `let mut $q = ..` already makes `$q` mutable; `$orig` still contains the
+    // original `mut` token too (it's spliced in unchanged), which would otherwise trigger an
+    // `unused_mut` warning on that now-redundant inner qualifier.
+    (Let, $orig:tt, $o:expr, $body:block, (), [$bind:ident], [mut $q:ident ,]) => {
+        #[allow(unused_mut)]
+        let mut $q = if let $orig = $o { $bind } else { $body };
+    };
+    (Let, $orig:tt, $o:expr, $body:block, (), [$bind:ident], [$q:ident ,]) => {
+        let $q = if let $orig = $o { $bind } else { $body };
+    };
+    (Let, $orig:tt, $o:expr, $body:block, (), [$($bind:ident),*], [$($qual:tt)*]) => {
+        #[allow(unused_mut)]
+        let ($($qual)*) = if let $orig = $o { ($($bind),*) } else { $body };
+    };
+
+    // With an error binding: the scrutinee is a `Result`, and the `Err` payload is bound to `$e`
+    // instead of being discarded, so `$body` can inspect it.
+    (Expr, $orig:tt, $o:expr, $body:block, ($e:ident), [$bind:ident], [$($qual:tt)*]) => {
+        match $o { $orig => $bind, Err($e) => $body }
+    };
+    (Expr, $orig:tt, $o:expr, $body:block, ($e:ident), [$($bind:ident),*], [$($qual:tt)*]) => {
+        match $o { $orig => ($($bind),*), Err($e) => $body }
+    };
+    (Let, $orig:tt, $o:expr, $body:block, ($e:ident), [$bind:ident], [mut $q:ident ,]) => {
+        #[allow(unused_mut)]
+        let mut $q = match $o { $orig => $bind, Err($e) => $body };
+    };
+    (Let, $orig:tt, $o:expr, $body:block, ($e:ident), [$bind:ident], [$q:ident ,]) => {
+        let $q = match $o { $orig => $bind, Err($e) => $body };
+    };
+    (Let, $orig:tt, $o:expr, $body:block, ($e:ident), [$($bind:ident),*], [$($qual:tt)*]) => {
+        #[allow(unused_mut)]
+        let ($($qual)*) = match $o { $orig => ($($bind),*), Err($e) => $body };
+    };
+}
+
+// Internal: the binding-collection tt-muncher shared by `ward!`/`guard!` and `ward_unwrap!`/
+// `guard_unwrap!`. It walks a pattern's token tree and, once there's nothing left to scan, hands
+// the result to `$finish` (one of `__ward_finish_done!`/`__ward_unwrap_finish_done!`) along with
+// `$fargs`, an opaque bag of tokens threaded through unchanged that each caller uses to carry
+// whatever else its own terminal expansion needs (the scrutinee, the `else`/panic payload, ...).
+//
+// Two accumulators are built in lockstep as the walk proceeds:
+// - `$bind`: the plain identifier for each binding, used to read the matched values back out of
+//   the `if let`/`match` arm (a `mut` qualifier doesn't change how a binding is *read*).
+// - `$qual`: the same identifiers, but with any `mut` qualifier the pattern declared them with
+//   still attached and a trailing comma after each one, e.g. `mut x ,` or `y ,`. This is what the
+//   hoisted outer `let` re-declares with, so a `mut` in the pattern isn't silently dropped the
+//   moment the value is copied out into the enclosing scope.
+//
+// The walk itself emits a name for each plain `ident` and `ref`/`mut ident` binding, recurses into
+// tuple/slice/struct-pattern subpatterns, skips `_`/`..`, and skips path segments of enum/struct
+// constructors (so `Ok`/`Point` themselves are never collected), while still collecting struct
+// field shorthand (the `x`/`y` in `Point { x, y }`).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_bind_munch {
+    // Done scanning: hand the collected bindings back to the caller's terminal macro.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*],) => {
+        $crate::$finish!($($fargs)*, [$($bind),*], [$($qual)*]);
+    };
+
+    // `_` and `..` never introduce a binding.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], _ $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], .. $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+
+    // `true`/`false` are literals, but `$id:ident` also matches them, so skip them here before
+    // the identifier arms below can collect them as bindings.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], true $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], false $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+
+    // `ref` doesn't itself introduce a name, and doesn't affect how the outer binding is
+    // re-declared, so it's simply dropped.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], ref $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+
+    // `mut $id`: unlike `ref`, this has to survive into `$qual` so the hoisted outer binding is
+    // declared `mut` too. `mut` always directly precedes the identifier it qualifies, so it's
+    // collected as one atomic step, mirroring the plain-identifier arms below.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], mut $id:ident, $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind,)* $id], [$($qual)* mut $id ,], $($rest)*)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], mut $id:ident @ $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind,)* $id], [$($qual)* mut $id ,], $($rest)*)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], mut $id:ident) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind,)* $id], [$($qual)* mut $id ,],)
+    };
+
+    // A leading `::` with no preceding segment (absolute paths).
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], :: $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+
+    // A path's final segment, e.g. the `Foo` in `MyEnum::Foo` used as a whole (unit-variant)
+    // pattern: like the path segments before it, it's never a binding, and there's nothing left
+    // after it to scan. This has to come before the general path-segment arm below, since that
+    // one would otherwise recurse with just `Foo` left over and hand it to the plain-identifier
+    // arm further down, wrongly treating it as a fresh binding.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident :: $seg:ident) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*],)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident :: $seg:ident, $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+
+    // A path segment, e.g. the `Foo` in `Foo::Bar(x)`: never a binding by itself.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident :: $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+
+    // `Path(...)` / `Path { ... }`: a tuple-struct/enum or struct pattern. The path itself
+    // isn't a binding; flatten the interior so it gets scanned like anything else.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident ( $($inner:tt)* ) $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($inner)* $($rest)*)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident { $($inner:tt)* } $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($inner)* $($rest)*)
+    };
+
+    // A bare group, e.g. a tuple or slice subpattern not preceded by a path.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], ( $($inner:tt)* ) $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($inner)* $($rest)*)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], [ $($inner:tt)* ] $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($inner)* $($rest)*)
+    };
+
+    // `name @ subpat`: `name` binds, then keep scanning the subpattern.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident @ $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind,)* $id], [$($qual)* $id ,], $($rest)*)
+    };
+
+    // `field: subpat` in a struct pattern: `field` is a field name, not a binding.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident : $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+
+    // A plain identifier in binding position, including struct field shorthand
+    // (the `x` in `Point { x, y }`).
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident, $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind,)* $id], [$($qual)* $id ,], $($rest)*)
+    };
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $id:ident) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind,)* $id], [$($qual)* $id ,],)
+    };
+
+    // Anything else (literals, `&`, `|`, range operators, ...) never binds.
+    ($finish:ident, [$($fargs:tt)*], [$($bind:ident),*], [$($qual:tt)*], $_skip:tt $($rest:tt)*) => {
+        $crate::__ward_bind_munch!($finish, [$($fargs)*], [$($bind),*], [$($qual)*], $($rest)*)
+    };
+}
+
+/// Like [`ward!`], but panics instead of returning early or running an `else` branch when the
+/// pattern doesn't match. This is the guaranteed-unwrap analogue of `ward!`, for the places where
+/// a non-match is a bug rather than something to recover from.
+///
+/// The panic message mirrors `assert_eq!`: it prints the stringified scrutinee and, since the
+/// scrutinee must be `Debug`, the value that actually showed up. An optional trailing
+/// `"message", args...` form, mirroring `assert!`'s second form, overrides the message entirely.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// let sut = Some("test");
+/// let res = ward_unwrap!(sut);
+/// assert_eq!(res, "test");
+/// # }
+/// ```
+/// ```should_panic
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // Panics with "ward_unwrap!(sut) failed: expected `Some(_)`, got None"
+/// let sut: Option<&str> = None;
+/// ward_unwrap!(sut);
+/// # }
+/// ```
+/// ```should_panic
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // The trailing form replaces the panic message entirely, just like assert!'s second form.
+/// let path = "ward.toml";
+/// let sut: Option<&str> = None;
+/// ward_unwrap!(sut, "config must be present: {}", path);
+/// # }
+/// ```
+/// ```should_panic
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // Works with any refutable pattern, not just an implied Some(x).
+/// let sut: Result<&str, &str> = Err("nope");
+/// ward_unwrap!(Ok(x) = sut);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! ward_unwrap {
+    ($($t:tt)+) => { $crate::__ward_unwrap_split!(Expr, [] $($t)+) };
+}
+
+/// Like [`guard!`], but panics instead of returning early or running an `else` branch when the
+/// pattern doesn't match, hoisting every binding the pattern introduces into the enclosing scope.
+/// This is the guaranteed-unwrap analogue of `guard!`.
+///
+/// See [`ward_unwrap!`] for the panic message format and the custom-message form.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// let sut = Some("test");
+/// guard_unwrap!(let res = sut);
+/// assert_eq!(res, "test");
+/// # }
+/// ```
+/// ```should_panic
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// let sut: Option<&str> = None;
+/// guard_unwrap!(let res = sut);
+/// # }
+/// ```
+/// ```should_panic
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// let sut: Option<&str> = None;
+/// guard_unwrap!(let res = sut, "config must be present");
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // A `mut` qualifier on a sub-binding carries over to the hoisted outer variable.
+/// let sut: Result<i32, &str> = Ok(1);
+/// guard_unwrap!(let Ok(mut x) = sut);
+/// x += 1;
+/// assert_eq!(x, 2);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! guard_unwrap {
+    (let $result:ident = $o:expr) => { let $result = $crate::ward_unwrap!($o); };
+    (let $result:ident = $o:expr, $($msg:tt)+) => { let $result = $crate::ward_unwrap!($o, $($msg)+); };
+    (let mut $result:ident = $o:expr) => { let mut $result = $crate::ward_unwrap!($o); };
+    (let mut $result:ident = $o:expr, $($msg:tt)+) => { let mut $result = $crate::ward_unwrap!($o, $($msg)+); };
+
+    (let $($t:tt)+) => { $crate::__ward_unwrap_split!(Let, [] $($t)+) };
+}
+
+// Internal: the `ward_unwrap!`/`guard_unwrap!` counterpart of `__ward_split!`. Identical
+// token-scanning strategy (see the comment there for why this can't just be a `$(tt)+ = $o:expr`
+// arm), but hands off to `__ward_unwrap_finish!` and additionally tracks a "display" form of the
+// pattern for the panic message: for the implied `Option` shorthand that's the literal
+// `Some(_)`, since the real pattern uses an internal placeholder name the user never wrote.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_unwrap_split {
+    ($mode:tt, [$($pat:tt)+] = $($rest:tt)*) => {
+        $crate::__ward_unwrap_finish!($mode, ($($pat)+), stringify!($($pat)+), $($rest)*)
+    };
+    ($mode:tt, [$($pat:tt)*] , $($rest:tt)*) => {
+        $crate::__ward_unwrap_finish!($mode, (Some(__ward_bound)), "Some(_)", $($pat)*, $($rest)*)
+    };
+    ($mode:tt, [$($pat:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__ward_unwrap_split!($mode, [$($pat)* $next] $($rest)*)
+    };
+    ($mode:tt, [$($pat:tt)*]) => {
+        $crate::__ward_unwrap_finish!($mode, (Some(__ward_bound)), "Some(_)", $($pat)*)
+    };
+}
+
+// Internal: parses the scrutinee and the optional trailing `"message", args...` form (mirroring
+// `assert!`'s second form), then hands off to the unwrap muncher.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_unwrap_finish {
+    ($mode:tt, $orig:tt, $disp:expr, $o:expr) => {
+        $crate::__ward_unwrap_munch!($mode, [], $orig, $disp, $o, ())
+    };
+    ($mode:tt, $orig:tt, $disp:expr, $o:expr, $($msg:tt)+) => {
+        $crate::__ward_unwrap_munch!($mode, [], $orig, $disp, $o, ($($msg)+))
+    };
+}
+
+// Internal: the `ward_unwrap!`/`guard_unwrap!` counterpart of `__ward_munch!`: unwraps the
+// parenthesized pattern and hands the raw tokens to the shared `__ward_bind_munch!` walk (see
+// its doc comment), with `__ward_unwrap_finish_done!` as the terminal.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_unwrap_munch {
+    // See the matching arm in `__ward_munch!` for why a lone identifier is always zero bindings.
+    ($mode:tt, [], ($id:ident), $disp:expr, $o:expr, $msg:tt) => {
+        $crate::__ward_bind_munch!(
+            __ward_unwrap_finish_done,
+            [$mode, ($id), $disp, $o, $msg],
+            [],
+            [],
+        )
+    };
+    ($mode:tt, [], ($($orig:tt)+), $disp:expr, $o:expr, $msg:tt) => {
+        $crate::__ward_bind_munch!(
+            __ward_unwrap_finish_done,
+            [$mode, ($($orig)+), $disp, $o, $msg],
+            [],
+            [],
+            $($orig)+
+        )
+    };
+}
+
+// Internal: the terminal step of `__ward_bind_munch!` for `ward_unwrap!`/`guard_unwrap!`: a
+// `match` whose fallback arm either panics with a message built from the stringified scrutinee
+// and its `Debug` value, or with the user-supplied message. `Let` mode re-declares each binding
+// with its original `mut` qualifier (see `__ward_finish_done!` for why).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ward_unwrap_finish_done {
+    // No custom message: panic with the stringified scrutinee/pattern and the actual value's
+    // `Debug` representation.
+    (Expr, $orig:tt, $disp:expr, $o:expr, (), [$bind:ident], [$($qual:tt)*]) => {
+        match $o {
+            $orig => $bind,
+            ref __ward_other => panic!(
+                "ward_unwrap!({}) failed: expected `{}`, got {:?}",
+                stringify!($o), $disp, __ward_other
+            ),
+        }
+    };
+    (Expr, $orig:tt, $disp:expr, $o:expr, (), [$($bind:ident),*], [$($qual:tt)*]) => {
+        match $o {
+            $orig => ($($bind),*),
+            ref __ward_other => panic!(
+                "ward_unwrap!({}) failed: expected `{}`, got {:?}",
+                stringify!($o), $disp, __ward_other
+            ),
+        }
+    };
+    // The outer `let mut $q = ..` already makes `$q` mutable; `$orig` still contains the
+    // original `mut` token too (it's spliced in unchanged), which would otherwise trigger an
+    // `unused_mut` warning on that now-redundant inner qualifier.
+    (Let, $orig:tt, $disp:expr, $o:expr, (), [$bind:ident], [mut $q:ident ,]) => {
+        #[allow(unused_mut)]
+        let mut $q = match $o {
+            $orig => $bind,
+            ref __ward_other => panic!(
+                "guard_unwrap!(let {} = {}) failed: got {:?}",
+                $disp, stringify!($o), __ward_other
+            ),
+        };
+    };
+    (Let, $orig:tt, $disp:expr, $o:expr, (), [$bind:ident], [$q:ident ,]) => {
+        let $q = match $o {
+            $orig => $bind,
+            ref __ward_other => panic!(
+                "guard_unwrap!(let {} = {}) failed: got {:?}",
+                $disp, stringify!($o), __ward_other
+            ),
+        };
+    };
+    (Let, $orig:tt, $disp:expr, $o:expr, (), [$($bind:ident),*], [$($qual:tt)*]) => {
+        #[allow(unused_mut)]
+        let ($($qual)*) = match $o {
+            $orig => ($($bind),*),
+            ref __ward_other => panic!(
+                "guard_unwrap!(let {} = {}) failed: got {:?}",
+                $disp, stringify!($o), __ward_other
+            ),
+        };
+    };
+
+    // Custom message: panic with exactly what the caller asked for.
+    (Expr, $orig:tt, $disp:expr, $o:expr, ($($msg:tt)+), [$bind:ident], [$($qual:tt)*]) => {
+        match $o { $orig => $bind, _ => panic!($($msg)+) }
+    };
+    (Expr, $orig:tt, $disp:expr, $o:expr, ($($msg:tt)+), [$($bind:ident),*], [$($qual:tt)*]) => {
+        match $o { $orig => ($($bind),*), _ => panic!($($msg)+) }
+    };
+    (Let, $orig:tt, $disp:expr, $o:expr, ($($msg:tt)+), [$bind:ident], [mut $q:ident ,]) => {
+        #[allow(unused_mut)]
+        let mut $q = match $o { $orig => $bind, _ => panic!($($msg)+) };
+    };
+    (Let, $orig:tt, $disp:expr, $o:expr, ($($msg:tt)+), [$bind:ident], [$q:ident ,]) => {
+        let $q = match $o { $orig => $bind, _ => panic!($($msg)+) };
+    };
+    (Let, $orig:tt, $disp:expr, $o:expr, ($($msg:tt)+), [$($bind:ident),*], [$($qual:tt)*]) => {
+        #[allow(unused_mut)]
+        let ($($qual)*) = match $o { $orig => ($($bind),*), _ => panic!($($msg)+) };
+    };
+}
+
+/// Returns early from the function unless `$cond` is true. Can alternatively have an `else`
+/// branch, or an alternative "early return" statement, like `break` or `continue` for loops, e.g.
+/// This is the boolean-condition counterpart of [`ward!`], for the "test arguments and bail
+/// early" shape that doesn't have an `Option`/`Result` to match against.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // Nothing happens when the condition holds.
+/// ensure!(1 < 2);
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // When the condition is false, ensure! returns early.
+/// ensure!(1 > 2);
+/// unreachable!();
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // Because the condition is false, the else branch will be run. When the else branch is
+/// // invoked, ensure! no longer automatically returns early for you, so you must do so yourself
+/// // if you want it.
+/// ensure!(1 > 2, else {
+///     println!("This code will run!");
+///     return;
+/// });
+/// unreachable!();
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// fn get(index: usize, len: usize) -> Option<usize> {
+///     ensure!(index < len, else { return None });
+///     Some(index)
+/// }
+/// assert_eq!(get(1, 2), Some(1));
+/// assert_eq!(get(5, 2), None);
+/// # }
+/// ```
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// // You can use ensure! with a different "early return" statement, such as break for loops
+/// let mut sut = 0;
+/// loop {
+///     ensure!(sut < 5, break);
+///     sut += 1;
+/// }
+/// assert_eq!(sut, 5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr) => { $crate::ensure!($cond, else { return; }) };
+    ($cond:expr, else $body:block) => {
+        if !($cond) { $body }
+    };
+    ($cond:expr, $early:stmt) => { $crate::ensure!($cond, else { $early }) };
+}
+
+/// Alias for [`ensure!`], so that the boolean-condition guard reads like a sibling of
+/// [`ward!`]/[`guard!`] rather than a one-off addition: `ward`/`guard` match a pattern,
+/// `guard_that` checks a condition.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ward;
+/// #
+/// # fn main() {
+/// fn get(index: usize, len: usize) -> Option<usize> {
+///     guard_that!(index < len, else { return None });
+///     Some(index)
+/// }
+/// assert_eq!(get(1, 2), Some(1));
+/// assert_eq!(get(5, 2), None);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! guard_that {
+    ($($t:tt)+) => { $crate::ensure!($($t)+) };
 }